@@ -4,30 +4,122 @@
 // MERCHANTIBILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
 // Lesser General Public License `LICENSE` for details.
 
-use std::f64::consts::{FRAC_PI_2};
+// With the `libm` feature enabled, math ops go through `libm` instead of
+// `std`, and the crate builds `no_std`.
+#![cfg_attr(feature = "libm", no_std)]
+
+#[cfg(feature = "libm")]
+extern crate libm;
+#[cfg(feature = "libm")]
+extern crate alloc;
+
+#[cfg(feature = "libm")]
+use core::ops::{Add, Sub, Mul, Div, Neg};
+#[cfg(not(feature = "libm"))]
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+#[cfg(feature = "libm")]
+use alloc::{boxed::Box, vec::Vec};
+
+// The numeric operations the easers need, implemented for both `f32` and
+// `f64` so callers can pick whichever precision suits them (e.g. `f32`
+// for graphics/embedded code). Only `zero`/`one`/`from_u64`/`from_f64`/
+// `step_ratio`/`frac_pi_2`/`epsilon`/`abs` and the transcendental functions
+// are required; the rest are constants derived from those.
+pub trait Float:
+    Copy + PartialOrd +
+    Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_u64(v: u64) -> Self;
+    fn from_f64(v: f64) -> Self;
+    fn step_ratio(step: u64, steps: u64) -> Self;
+    fn frac_pi_2() -> Self;
+    fn epsilon() -> Self;
+    fn sin(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp2(self) -> Self;
+    fn abs(self) -> Self;
+
+    fn two() -> Self { Self::from_u64(2) }
+    fn three() -> Self { Self::from_u64(3) }
+    fn four() -> Self { Self::from_u64(4) }
+    fn five() -> Self { Self::from_u64(5) }
+    fn six() -> Self { Self::from_u64(6) }
+    fn eight() -> Self { Self::from_u64(8) }
+    fn ten() -> Self { Self::from_u64(10) }
+    fn twenty() -> Self { Self::from_u64(20) }
+    fn half() -> Self { Self::one() / Self::two() }
+    fn pi() -> Self { Self::frac_pi_2() + Self::frac_pi_2() }
+}
+
+macro_rules! impl_float {
+    ($t:ident, $std_mod:ident, $epsilon:expr, $sin:path, $sqrt:path, $exp2:path, $abs:path) => (
+        impl Float for $t {
+            fn zero() -> Self { 0 as $t }
+            fn one() -> Self { 1 as $t }
+            fn from_u64(v: u64) -> Self { v as $t }
+            fn from_f64(v: f64) -> Self { v as $t }
+            fn step_ratio(step: u64, steps: u64) -> Self { step as $t / steps as $t }
+
+            #[cfg(feature = "libm")]
+            fn frac_pi_2() -> Self { ::core::$std_mod::consts::FRAC_PI_2 }
+            #[cfg(not(feature = "libm"))]
+            fn frac_pi_2() -> Self { ::std::$std_mod::consts::FRAC_PI_2 }
+
+            fn epsilon() -> Self { $epsilon }
+
+            #[cfg(feature = "libm")]
+            fn sin(self) -> Self { $sin(self) }
+            #[cfg(not(feature = "libm"))]
+            fn sin(self) -> Self { self.sin() }
+
+            #[cfg(feature = "libm")]
+            fn sqrt(self) -> Self { $sqrt(self) }
+            #[cfg(not(feature = "libm"))]
+            fn sqrt(self) -> Self { self.sqrt() }
+
+            #[cfg(feature = "libm")]
+            fn exp2(self) -> Self { $exp2(self) }
+            #[cfg(not(feature = "libm"))]
+            fn exp2(self) -> Self { self.exp2() }
+
+            #[cfg(feature = "libm")]
+            fn abs(self) -> Self { $abs(self) }
+            #[cfg(not(feature = "libm"))]
+            fn abs(self) -> Self { self.abs() }
+        }
+    )
+}
+
+impl_float!(f32, f32, 1e-6,
+    libm::sinf, libm::sqrtf, libm::exp2f, libm::fabsf);
+impl_float!(f64, f64, 1e-6,
+    libm::sin, libm::sqrt, libm::exp2, libm::fabs);
 
 macro_rules! easer {
     ($f:ident, $t:ident, $e:expr) => (
-        pub struct $t {
-            start: f64,
-            dist: f64,
+        pub struct $t<T: Float = f64> {
+            start: T,
+            dist: T,
             step: u64,
             steps: u64,
         }
 
-        pub fn $f(start: f64, end: f64, steps: u64) -> $t {
+        pub fn $f<T: Float>(start: T, end: T, steps: u64) -> $t<T> {
             $t {start: start, dist: end-start, step: 0, steps: steps}
         }
 
-        impl Iterator for $t {
-            type Item = f64;
+        impl<T: Float> Iterator for $t<T> {
+            type Item = T;
 
-            fn next(&mut self) -> Option<f64> {
+            fn next(&mut self) -> Option<T> {
                 self.step += 1;
                 if self.step > self.steps {
                     None
                 } else {
-                    let x = self.step as f64 / self.steps as f64;
+                    let x = T::step_ratio(self.step, self.steps);
                     Some($e(x) * self.dist + self.start)
                 }
             }
@@ -35,74 +127,306 @@ macro_rules! easer {
     )
 }
 
-easer!(linear, Linear, |x:f64| {
+easer!(linear, Linear, |x: T| {
     x
 });
-easer!(quad_in, QuadIn, |x:f64| {
+easer!(quad_in, QuadIn, |x: T| {
     x * x
 });
-easer!(quad_out, QuadOut, |x:f64| {
-    -(x * (x - 2.))
+easer!(quad_out, QuadOut, |x: T| {
+    -(x * (x - T::two()))
 });
-easer!(quad_inout, QuadInOut, |x:f64| {
-    if x < 0.5 { 2. * x * x }
-    else { (-2. * x * x) + (4. * x) - 1. }
+easer!(quad_inout, QuadInOut, |x: T| {
+    if x < T::half() { T::two() * x * x }
+    else { (-T::two() * x * x) + (T::four() * x) - T::one() }
 });
-easer!(cubic_in, CubicIn, |x:f64| {
+easer!(cubic_in, CubicIn, |x: T| {
     x * x * x
 });
-easer!(cubic_out, CubicOut, |x:f64| {
-    let y = x - 1.;
-    y * y * y + 1.
+easer!(cubic_out, CubicOut, |x: T| {
+    let y = x - T::one();
+    y * y * y + T::one()
 });
-easer!(cubic_inout, CubicInOut, |x:f64| {
-    if x < 0.5 { 4. * x * x * x }
+easer!(cubic_inout, CubicInOut, |x: T| {
+    if x < T::half() { T::four() * x * x * x }
     else {
-        let y = (2. * x) - 2.;
-        0.5 * y * y * y + 1.
+        let y = (T::two() * x) - T::two();
+        T::half() * y * y * y + T::one()
     }
 });
-easer!(quartic_in, QuarticIn, |x:f64| {
+easer!(quartic_in, QuarticIn, |x: T| {
     x * x * x * x
 });
-easer!(quartic_out, QuarticOut, |x:f64| {
-    let y = x - 1.;
-    y * y * y * (1. - x) + 1.
+easer!(quartic_out, QuarticOut, |x: T| {
+    let y = x - T::one();
+    y * y * y * (T::one() - x) + T::one()
 });
-easer!(quartic_inout, QuarticInOut, |x:f64| {
-    if x < 0.5 { 8. * x * x * x * x }
+easer!(quartic_inout, QuarticInOut, |x: T| {
+    if x < T::half() { T::eight() * x * x * x * x }
     else {
-        let y = x - 1.;
-        -8. * y * y * y * y + 1.
+        let y = x - T::one();
+        -T::eight() * y * y * y * y + T::one()
+    }
+});
+easer!(sin_in, SinIn, |x: T| {
+    let y = (x - T::one()) * T::frac_pi_2();
+    y.sin() + T::one()
+});
+easer!(sin_out, SinOut, |x: T| {
+    (x * T::frac_pi_2()).sin()
+});
+easer!(sin_inout, SinInOut, |x: T| {
+    if x < T::half() { T::half() * (T::one() - (T::one() - T::four() * (x * x)).sqrt()) }
+    else             { T::half() * ((-((T::two() * x) - T::three()) * ((T::two() * x) - T::one())).sqrt() + T::one()) }
+});
+easer!(exp_in, ExpIn, |x: T| {
+    if x == T::zero() { T::zero() }
+    else               { (T::ten() * (x - T::one())).exp2() }
+});
+easer!(exp_out, ExpOut, |x: T| {
+    if x == T::one() { T::one() }
+    else              { T::one() - (-T::ten() * x).exp2() }
+});
+easer!(exp_inout, ExpInOut, |x: T| {
+    if      x == T::one()  { T::one() }
+    else if x == T::zero() { T::zero() }
+    else if x < T::half()  { T::half() * ((T::twenty() * x) - T::ten()).exp2() }
+    else                   { -T::half() * ((-T::twenty() * x) + T::ten()).exp2() + T::one() }
+});
+
+easer!(back_in, BackIn, |x: T| {
+    let s = T::from_f64(1.70158);
+    x * x * ((s + T::one()) * x - s)
+});
+easer!(back_out, BackOut, |x: T| {
+    let s = T::from_f64(1.70158);
+    let y = x - T::one();
+    (s + T::one()) * y * y * y + s * y * y + T::one()
+});
+easer!(back_inout, BackInOut, |x: T| {
+    let s = T::from_f64(1.70158) * T::from_f64(1.525);
+    if x < T::half() {
+        let y = T::two() * x;
+        T::half() * (y * y * ((s + T::one()) * y - s))
+    } else {
+        let y = (T::two() * x) - T::two();
+        T::half() * (y * y * ((s + T::one()) * y + s) + T::two())
     }
 });
-easer!(sin_in, SinIn, |x:f64| {
-    let y = (x - 1.) * FRAC_PI_2;
-    y.sin() + 1.
+
+fn elastic_in_raw<T: Float>(x: T) -> T {
+    -((T::ten() * (x - T::one())).exp2()) * ((x - T::from_f64(1.1)) * T::five() * T::pi()).sin()
+}
+
+fn elastic_out_raw<T: Float>(x: T) -> T {
+    T::one() - (-T::ten() * x).exp2() * ((x + T::from_f64(0.1)) * T::five() * T::pi()).sin()
+}
+
+easer!(elastic_in, ElasticIn, |x: T| {
+    if      x == T::zero() { T::zero() }
+    else if x == T::one()  { T::one() }
+    else                    { elastic_in_raw(x) }
 });
-easer!(sin_out, SinOut, |x:f64| {
-    (x * FRAC_PI_2).sin()
+easer!(elastic_out, ElasticOut, |x: T| {
+    if      x == T::zero() { T::zero() }
+    else if x == T::one()  { T::one() }
+    else                    { elastic_out_raw(x) }
 });
-easer!(sin_inout, SinInOut, |x:f64| {
-    if x < 0.5 { 0.5 * (1. - (1. - 4. * (x * x)).sqrt()) }
-    else       { 0.5 * ((-((2. * x) - 3.) * ((2. * x) - 1.)).sqrt() + 1.) }
+easer!(elastic_inout, ElasticInOut, |x: T| {
+    if      x == T::zero() { T::zero() }
+    else if x == T::one()  { T::one() }
+    else if x < T::half()  { T::half() * elastic_in_raw(T::two() * x) }
+    else                    { T::half() * elastic_out_raw((T::two() * x) - T::one()) + T::half() }
 });
-easer!(exp_in, ExpIn, |x:f64| {
-    if x == 0. { 0. }
-    else       { (10. * (x - 1.)).exp2() }
+
+// The ease-out curve is four parabolic segments over
+// [0, 1/2.75), [1/2.75, 2/2.75), [2/2.75, 2.5/2.75) and [2.5/2.75, 1];
+// ease-in and in-out are built from it per the usual Penner identities.
+fn bounce_out_raw<T: Float>(x: T) -> T {
+    let n1 = T::from_f64(7.5625);
+    let d1 = T::from_f64(2.75);
+    if x < T::one() / d1 {
+        n1 * x * x
+    } else if x < T::two() / d1 {
+        let y = x - T::from_f64(1.5) / d1;
+        n1 * y * y + T::from_f64(0.75)
+    } else if x < T::from_f64(2.5) / d1 {
+        let y = x - T::from_f64(2.25) / d1;
+        n1 * y * y + T::from_f64(0.9375)
+    } else {
+        let y = x - T::from_f64(2.625) / d1;
+        n1 * y * y + T::from_f64(0.984375)
+    }
+}
+
+easer!(bounce_in, BounceIn, |x: T| {
+    T::one() - bounce_out_raw(T::one() - x)
 });
-easer!(exp_out, ExpOut, |x:f64| {
-    if x == 1. { 1. }
-    else       { 1. - (-10. * x).exp2() }
+easer!(bounce_out, BounceOut, |x: T| {
+    bounce_out_raw(x)
 });
-easer!(exp_inout, ExpInOut, |x:f64| {
-    if      x == 1. { 1. }
-    else if x == 0. { 0. }
-    else if x < 0.5 { 0.5 * ((20. * x) - 10.).exp2() }
-    else            { -0.5 * ((-20. * x) + 10.).exp2() + 1. }
+easer!(bounce_inout, BounceInOut, |x: T| {
+    if x < T::half() {
+        (T::one() - bounce_out_raw(T::one() - T::two() * x)) * T::half()
+    } else {
+        (T::one() + bounce_out_raw(T::two() * x - T::one())) * T::half()
+    }
 });
 
-#[cfg(test)]
+// Mirrors the CSS `cubic-bezier()` timing function, with P0=(0,0) and
+// P3=(1,1) implicit.
+pub struct CubicBezier<T: Float = f64> {
+    start: T,
+    dist: T,
+    step: u64,
+    steps: u64,
+    x1: T,
+    y1: T,
+    x2: T,
+    y2: T,
+}
+
+fn clamp01<T: Float>(v: T) -> T {
+    if v < T::zero() { T::zero() }
+    else if v > T::one() { T::one() }
+    else { v }
+}
+
+pub fn cubic_bezier<T: Float>(start: T, end: T, steps: u64, x1: T, y1: T, x2: T, y2: T) -> CubicBezier<T> {
+    CubicBezier {
+        start: start,
+        dist: end - start,
+        step: 0,
+        steps: steps,
+        x1: clamp01(x1),
+        y1: y1,
+        x2: clamp01(x2),
+        y2: y2,
+    }
+}
+
+impl<T: Float> CubicBezier<T> {
+    fn sample_x(&self, t: T) -> T {
+        let u = T::one() - t;
+        T::three() * u * u * t * self.x1 + T::three() * u * t * t * self.x2 + t * t * t
+    }
+
+    fn sample_y(&self, t: T) -> T {
+        let u = T::one() - t;
+        T::three() * u * u * t * self.y1 + T::three() * u * t * t * self.y2 + t * t * t
+    }
+
+    fn sample_dx(&self, t: T) -> T {
+        T::three() * (T::one() - t) * (T::one() - t) * self.x1
+            + T::six() * (T::one() - t) * t * (self.x2 - self.x1)
+            + T::three() * t * t * (T::one() - self.x2)
+    }
+
+    // Inverts x -> t with Newton-Raphson, falling back to bisection
+    // whenever the derivative is too small or t escapes [0, 1].
+    fn solve_t(&self, x: T) -> T {
+        let mut t = x;
+        for _ in 0..8 {
+            let dx = self.sample_dx(t);
+            if dx.abs() < T::epsilon() {
+                break;
+            }
+            let next_t = t - (self.sample_x(t) - x) / dx;
+            if next_t < T::zero() || next_t > T::one() {
+                break;
+            }
+            t = next_t;
+        }
+
+        if (self.sample_x(t) - x).abs() < T::epsilon() {
+            return t;
+        }
+
+        let mut lo = T::zero();
+        let mut hi = T::one();
+        let mut t = x;
+        while (self.sample_x(t) - x).abs() > T::epsilon() {
+            if self.sample_x(t) < x {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            t = T::half() * (lo + hi);
+        }
+        t
+    }
+}
+
+impl<T: Float> Iterator for CubicBezier<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.step += 1;
+        if self.step > self.steps {
+            None
+        } else {
+            let x = T::step_ratio(self.step, self.steps);
+            let y = if x == T::zero() {
+                T::zero()
+            } else if x == T::one() {
+                T::one()
+            } else {
+                self.sample_y(self.solve_t(x))
+            };
+            Some(y * self.dist + self.start)
+        }
+    }
+}
+
+// Sequences multiple easers into one continuous animation, each segment
+// starting where the previous one left off. Segments are boxed since
+// each easer call produces a distinct concrete type.
+pub struct Timeline<T: Float + 'static = f64> {
+    current: T,
+    segments: Vec<Box<dyn Iterator<Item = T>>>,
+    cursor: usize,
+    len: usize,
+}
+
+impl<T: Float + 'static> Timeline<T> {
+    pub fn new(start: T) -> Timeline<T> {
+        Timeline { current: start, segments: Vec::new(), cursor: 0, len: 0 }
+    }
+
+    pub fn then<F, I>(mut self, end: T, steps: u64, easer: F) -> Timeline<T>
+    where
+        F: FnOnce(T, T, u64) -> I,
+        I: Iterator<Item = T> + 'static,
+    {
+        self.segments.push(Box::new(easer(self.current, end, steps)));
+        self.current = end;
+        self.len += steps as usize;
+        self
+    }
+}
+
+impl<T: Float + 'static> Iterator for Timeline<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.cursor < self.segments.len() {
+            if let Some(v) = self.segments[self.cursor].next() {
+                self.len -= 1;
+                return Some(v);
+            }
+            self.cursor += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Float + 'static> ExactSizeIterator for Timeline<T> {}
+
+#[cfg(all(test, not(feature = "libm")))]
 mod test {
     use super::*;
 
@@ -114,6 +438,13 @@ mod test {
         (x * 10E+5).round() / 10E+5
     }
 
+    // f32 has far fewer significant digits than f64, so the f32
+    // instantiations are checked to two decimal places instead of the
+    // six that `round_5` keeps (10E+5 is 1e6).
+    fn round_2(x: f32) -> f32 {
+        (x * 100.).round() / 100.
+    }
+
     #[test]
     fn linear_test() {
         let model = vec![
@@ -150,6 +481,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn quad_in_f32_test() {
+        let model: Vec<f32> = vec![
+            100., 400., 900., 1600., 2500., 3600., 4900., 6400., 8100., 10000.,
+        ];
+        let try: Vec<f32> = quad_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn quad_out_test() {
         let model = vec![
@@ -168,6 +508,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn quad_out_f32_test() {
+        let model: Vec<f32> = vec![
+            1900., 3600., 5100., 6400., 7500., 8400., 9100., 9600., 9900., 10000.,
+        ];
+        let try: Vec<f32> = quad_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn quad_inout_test() {
         let model = vec![
@@ -186,6 +535,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn quad_inout_f32_test() {
+        let model: Vec<f32> = vec![
+            200., 800., 1800., 3200., 5000., 6800., 8200., 9200., 9800., 10000.,
+        ];
+        let try: Vec<f32> = quad_inout(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn cubic_in_test() {
         let model = vec![
@@ -204,6 +562,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn cubic_in_f32_test() {
+        let model: Vec<f32> = vec![
+            10., 80., 270., 640., 1250., 2160., 3430., 5120., 7290., 10000.,
+        ];
+        let try: Vec<f32> = cubic_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn cubic_out_test() {
         let model = vec![
@@ -222,6 +589,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn cubic_out_f32_test() {
+        let model: Vec<f32> = vec![
+            2710., 4880., 6570., 7840., 8750., 9360., 9730., 9920., 9990., 10000.,
+        ];
+        let try: Vec<f32> = cubic_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn quartic_in_test() {
         let model = vec![
@@ -240,6 +616,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn quartic_in_f32_test() {
+        let model: Vec<f32> = vec![
+            1., 16., 81., 256., 625., 1296., 2401., 4096., 6561., 10000.,
+        ];
+        let try: Vec<f32> = quartic_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn quartic_out_test() {
         let model = vec![
@@ -258,6 +643,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn quartic_out_f32_test() {
+        let model: Vec<f32> = vec![
+            3439., 5904., 7599., 8704., 9375., 9744., 9919., 9984., 9999., 10000.,
+        ];
+        let try: Vec<f32> = quartic_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn quartic_inout_test() {
         let model = vec![
@@ -276,6 +670,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn quartic_inout_f32_test() {
+        let model: Vec<f32> = vec![
+            8., 128., 648., 2048., 5000., 7952., 9352., 9872., 9992., 10000.,
+        ];
+        let try: Vec<f32> = quartic_inout(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn sin_in_test() {
         let model = vec![
@@ -294,6 +697,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn sin_in_f32_test() {
+        let model: Vec<f32> = vec![
+            123.12, 489.43, 1089.93, 1909.83, 2928.93, 4122.15, 5460.09, 6909.83, 8435.65, 10000.,
+        ];
+        let try: Vec<f32> = sin_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn sin_out_test() {
         let model = vec![
@@ -312,6 +724,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn sin_out_f32_test() {
+        let model: Vec<f32> = vec![
+            1564.34, 3090.17, 4539.91, 5877.85, 7071.07, 8090.17, 8910.07, 9510.57, 9876.88, 10000.,
+        ];
+        let try: Vec<f32> = sin_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn sin_inout_test() {
         let model = vec![
@@ -330,6 +751,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn sin_inout_f32_test() {
+        let model: Vec<f32> = vec![
+            101.02, 417.42, 1000., 2000., 5000., 8000., 9000., 9582.58, 9898.98, 10000.,
+        ];
+        let try: Vec<f32> = sin_inout(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn exp_in_test() {
         let model = vec![
@@ -348,6 +778,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn exp_in_f32_test() {
+        let model: Vec<f32> = vec![
+            19.53, 39.06, 78.13, 156.25, 312.5, 625., 1250., 2500., 5000., 10000.,
+        ];
+        let try: Vec<f32> = exp_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn exp_out_test() {
         let model = vec![
@@ -366,6 +805,15 @@ mod test {
         assert_eq!(try, model);
     }
 
+    #[test]
+    fn exp_out_f32_test() {
+        let model: Vec<f32> = vec![
+            5000., 7500., 8750., 9375., 9687.5, 9843.75, 9921.88, 9960.94, 9980.47, 10000.,
+        ];
+        let try: Vec<f32> = exp_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
     #[test]
     fn exp_inout_test() {
         let model = vec![
@@ -383,4 +831,298 @@ mod test {
         let try: Vec<f64> = exp_inout(0f64, 10000f64, 10).map(round_5).collect();
         assert_eq!(try, model);
     }
+
+    #[test]
+    fn exp_inout_f32_test() {
+        let model: Vec<f32> = vec![
+            19.53, 78.13, 312.5, 1250., 5000., 8750., 9687.5, 9921.88, 9980.47, 10000.,
+        ];
+        let try: Vec<f32> = exp_inout(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn back_in_test() {
+        let model = vec![
+            -143.1422,
+            -464.5056,
+            -801.9954,
+            -993.5168,
+            -876.975,
+            -290.2752,
+            928.6774,
+            2941.9776,
+            5911.7202,
+            10000.,
+        ];
+        let try: Vec<f64> = back_in(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn back_in_f32_test() {
+        let model: Vec<f32> = vec![
+            -143.14, -464.51, -802., -993.52, -876.98, -290.28, 928.68, 2941.98, 5911.72, 10000.,
+        ];
+        let try: Vec<f32> = back_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn back_out_test() {
+        let model = vec![
+            4088.2798,
+            7058.0224,
+            9071.3226,
+            10290.2752,
+            10876.975,
+            10993.5168,
+            10801.9954,
+            10464.5056,
+            10143.1422,
+            10000.,
+        ];
+        let try: Vec<f64> = back_out(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn back_out_f32_test() {
+        let model: Vec<f32> = vec![
+            4088.28, 7058.02, 9071.32, 10290.27, 10876.98, 10993.52, 10802., 10464.51, 10143.14, 10000.,
+        ];
+        let try: Vec<f32> = back_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn back_inout_test() {
+        let model = vec![
+            -375.18552,
+            -925.55656,
+            -788.33484,
+            899.25792,
+            5000.,
+            9100.74208,
+            10788.33484,
+            10925.55656,
+            10375.18552,
+            10000.,
+        ];
+        let try: Vec<f64> = back_inout(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn back_inout_f32_test() {
+        let model: Vec<f32> = vec![
+            -375.19, -925.56, -788.33, 899.26, 5000., 9100.74, 10788.34, 10925.56, 10375.19, 10000.,
+        ];
+        let try: Vec<f32> = back_inout(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn elastic_in_test() {
+        let model = vec![
+            0.,
+            39.0625,
+            0.,
+            -156.25,
+            0.,
+            625.,
+            0.,
+            -2500.,
+            0.,
+            10000.,
+        ];
+        let try: Vec<f64> = elastic_in(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn elastic_in_f32_test() {
+        let model: Vec<f32> = vec![
+            0., 39.06, 0., -156.25, 0., 625., 0., -2500., 0., 10000.,
+        ];
+        let try: Vec<f32> = elastic_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn elastic_out_test() {
+        let model = vec![
+            10000.,
+            12500.,
+            10000.,
+            9375.,
+            10000.,
+            10156.25,
+            10000.,
+            9960.9375,
+            10000.,
+            10000.,
+        ];
+        let try: Vec<f64> = elastic_out(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn elastic_out_f32_test() {
+        let model: Vec<f32> = vec![
+            10000., 12500., 10000., 9375., 10000., 10156.25, 10000., 9960.94, 10000., 10000.,
+        ];
+        let try: Vec<f32> = elastic_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn elastic_inout_test() {
+        let model = vec![
+            19.53125,
+            -78.125,
+            312.5,
+            -1250.,
+            5000.,
+            11250.,
+            9687.5,
+            10078.125,
+            9980.46875,
+            10000.,
+        ];
+        let try: Vec<f64> = elastic_inout(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn elastic_inout_f32_test() {
+        let model: Vec<f32> = vec![
+            19.53, -78.13, 312.5, -1250., 5000., 11250., 9687.5, 10078.13, 9980.47, 10000.,
+        ];
+        let try: Vec<f32> = elastic_inout(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn bounce_in_test() {
+        let model = vec![
+            118.75,
+            600.,
+            693.75,
+            2275.,
+            2343.75,
+            900.,
+            3193.75,
+            6975.,
+            9243.75,
+            10000.,
+        ];
+        let try: Vec<f64> = bounce_in(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn bounce_in_f32_test() {
+        let model: Vec<f32> = vec![
+            118.75, 600., 693.75, 2275., 2343.75, 900., 3193.75, 6975., 9243.75, 10000.,
+        ];
+        let try: Vec<f32> = bounce_in(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn bounce_out_test() {
+        let model = vec![
+            756.25,
+            3025.,
+            6806.25,
+            9100.,
+            7656.25,
+            7725.,
+            9306.25,
+            9400.,
+            9881.25,
+            10000.,
+        ];
+        let try: Vec<f64> = bounce_out(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn bounce_out_f32_test() {
+        let model: Vec<f32> = vec![
+            756.25, 3025., 6806.25, 9100., 7656.25, 7725., 9306.25, 9400., 9881.25, 10000.,
+        ];
+        let try: Vec<f32> = bounce_out(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn bounce_inout_test() {
+        let model = vec![
+            300.,
+            1137.5,
+            450.,
+            3487.5,
+            5000.,
+            6512.5,
+            9550.,
+            8862.5,
+            9700.,
+            10000.,
+        ];
+        let try: Vec<f64> = bounce_inout(0f64, 10000f64, 10).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn bounce_inout_f32_test() {
+        let model: Vec<f32> = vec![
+            300., 1137.5, 450., 3487.5, 5000., 6512.5, 9550., 8862.5, 9700., 10000.,
+        ];
+        let try: Vec<f32> = bounce_inout(0f32, 10000f32, 10).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn cubic_bezier_test() {
+        // CSS `ease` curve: cubic-bezier(0.25, 0.1, 0.25, 1.0)
+        let model = vec![
+            947.963057,
+            2952.443343,
+            5133.15161,
+            6825.40506,
+            8024.033876,
+            8852.293099,
+            9407.646143,
+            9756.253556,
+            9943.164775,
+            10000.,
+        ];
+        let try: Vec<f64> = cubic_bezier(0f64, 10000f64, 10, 0.25, 0.1, 0.25, 1.0).map(round_5).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn cubic_bezier_f32_test() {
+        let model: Vec<f32> = vec![
+            947.96, 2952.44, 5133.15, 6825.41, 8024.03, 8852.29, 9407.65, 9756.25, 9943.17, 10000.,
+        ];
+        let try: Vec<f32> = cubic_bezier(0f32, 10000f32, 10, 0.25, 0.1, 0.25, 1.0).map(round_2).collect();
+        assert_eq!(try, model);
+    }
+
+    #[test]
+    fn timeline_test() {
+        let timeline = Timeline::new(0f64).then(100., 3, quad_out).then(0., 2, bounce_out);
+        assert_eq!(timeline.len(), 5);
+
+        let up: Vec<f64> = quad_out(0f64, 100f64, 3).collect();
+        let down: Vec<f64> = bounce_out(100f64, 0f64, 2).collect();
+        let mut model = up;
+        model.extend(down);
+
+        let try: Vec<f64> = Timeline::new(0f64).then(100., 3, quad_out).then(0., 2, bounce_out).collect();
+        assert_eq!(try, model);
+    }
 }